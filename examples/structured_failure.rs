@@ -0,0 +1,35 @@
+/// Use [`Structured`] to emit machine-parseable failure telemetry.
+///
+/// `#[sysfail]` doesn't call [`structured::enter`] for you yet (see the
+/// module docs on `bevy_mod_sysfail::structured`), so this example calls it
+/// by hand at the top of the system body to get the `system`/`elapsed_ms`
+/// fields populated instead of the "not wired" placeholder.
+use bevy::prelude::*;
+use bevy_mod_sysfail::prelude::*;
+use bevy_mod_sysfail::structured;
+use thiserror::Error;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Update, check_inventory)
+        .run();
+}
+
+#[derive(Error, Debug)]
+enum InventoryError {
+    #[error("inventory is empty")]
+    Empty,
+}
+
+#[sysfail(Structured<InventoryError>)]
+fn check_inventory(time: Res<Time>) -> Result<(), InventoryError> {
+    let _system = structured::enter(std::any::type_name_of_val(&check_inventory));
+
+    let delta = time.delta_seconds_f64();
+    let current_time = time.elapsed_seconds_f64();
+    if current_time % 4. < delta {
+        Err(InventoryError::Empty)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! The [`Callsite`] trait, giving [`Failure`](crate::Failure) implementations
+//! access to the file/line/level of the system that failed.
+
+/// Metadata about where a `#[sysfail]` system failed.
+///
+/// The `#[sysfail]` macro generates a `'static` value implementing this trait
+/// for every decorated system, so that a [`Failure`](crate::Failure) can
+/// report *where* an error came from without the caller having to thread
+/// that information through manually.
+pub trait Callsite {
+    /// The underlying [`tracing::Metadata`] for this callsite.
+    fn metadata(&self) -> &'static tracing::Metadata<'static>;
+}
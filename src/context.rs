@@ -0,0 +1,63 @@
+//! A thread-local stack of narrative "what is the system doing right now"
+//! frames, surfaced as a breadcrumb trail when a `#[sysfail]` system fails.
+
+use std::cell::RefCell;
+
+thread_local! {
+    static FRAMES: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+/// Pops its frame off the context stack when dropped.
+///
+/// Returned by [`push`] and [`with_context!`]; hold onto it for as long as
+/// the frame should be considered active.
+#[must_use = "the context frame is popped when this guard is dropped"]
+pub struct ContextGuard(());
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        FRAMES.with(|frames| {
+            frames.borrow_mut().pop();
+        });
+    }
+}
+
+/// Push `frame` onto the current thread's context stack, returning a guard
+/// that pops it again once dropped. Prefer [`with_context!`], which builds
+/// the frame's message for you.
+pub fn push(frame: String) -> ContextGuard {
+    FRAMES.with(|frames| frames.borrow_mut().push(frame));
+    ContextGuard(())
+}
+
+/// Render the current thread's context stack as a breadcrumb trail, e.g.
+/// `[loading level forest] > [spawn enemies]`, or an empty string when the
+/// stack is empty.
+pub fn breadcrumb() -> String {
+    FRAMES.with(|frames| {
+        frames.borrow().iter().map(|frame| format!("[{frame}]")).collect::<Vec<_>>().join(" > ")
+    })
+}
+
+/// Push a narrative frame onto the context stack for the rest of the
+/// enclosing scope.
+///
+/// If a `#[sysfail]` system errors while the frame is active, the built-in
+/// `Failure`s prefix the logged message with the current breadcrumb trail,
+/// e.g. `[loading level forest] > [spawn enemies] error: ...`, so the log
+/// says what the system was doing, not just what broke. `Structured` does
+/// not prefix the message; it carries the trail in its `breadcrumb` field.
+///
+/// ```
+/// # use bevy_mod_sysfail::with_context;
+/// fn load_level(name: &str) {
+///     let _ctx = with_context!("loading level {name}");
+///     // ... any #[sysfail] system called from here carries the frame above
+/// }
+/// ```
+#[macro_export]
+macro_rules! with_context {
+    ($($arg:tt)*) => {
+        $crate::context::push(format!($($arg)*))
+    };
+}
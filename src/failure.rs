@@ -0,0 +1,105 @@
+//! The [`Failure`] trait and the handful of built-in implementations that
+//! ship with this crate.
+//!
+//! A `Failure` is what a `#[sysfail]` system's error is converted `Into`
+//! before being handled. It decides *what happens* when a system returns
+//! `Err` (or `None`): log it, print it on screen, crash the app, ignore it...
+
+use bevy::ecs::system::SystemParam;
+
+use crate::{Callsite, Level};
+
+pub mod chain;
+pub mod structured;
+pub mod syslog;
+pub mod tee;
+pub mod throttle;
+
+/// What to do with the error returned by a `#[sysfail]` system.
+///
+/// Implement this trait for your own type (see `examples/custom_failure.rs`)
+/// and use it as `#[sysfail(MyFailure)]`, or combine the decorators this
+/// crate ships with, such as [`throttle::Throttle`] or [`chain::Chain`].
+pub trait Failure {
+    /// Extra system state this `Failure` needs to handle the error, fetched
+    /// by the `#[sysfail]`-generated system just like any other parameter.
+    type Param: SystemParam;
+
+    /// The [`Level`] this `Failure` logs at, used by implementations that
+    /// need to pick a tracing level ahead of time (e.g. to decide whether to
+    /// even format the message).
+    const LEVEL: Level;
+
+    /// Called by the generated system when the wrapped system's body
+    /// returns an error convertible to `Self`.
+    fn handle_error(self, param: Self::Param, callsite: Option<&'static impl Callsite>);
+}
+
+/// Log the error through [`tracing`], at [`Self::LEVEL`](Failure::LEVEL).
+///
+/// This is the default `Failure` used when none is specified, i.e.
+/// `#[sysfail]` is sugar for `#[sysfail(Log<anyhow::Error>)]`.
+pub struct Log<T, Lvl = crate::Error>(pub T, std::marker::PhantomData<fn(Lvl)>);
+
+impl<T, Lvl> From<T> for Log<T, Lvl> {
+    fn from(error: T) -> Self {
+        Self(error, std::marker::PhantomData)
+    }
+}
+
+impl<T: std::fmt::Display, Lvl: crate::LogLevelModifier> Failure for Log<T, Lvl> {
+    type Param = ();
+
+    const LEVEL: Level = Lvl::LEVEL;
+
+    fn handle_error(self, _: (), callsite: Option<&'static impl Callsite>) {
+        log_at(Self::LEVEL, callsite, &self.0.to_string());
+    }
+}
+
+/// Silently drop the error.
+///
+/// Useful for systems where failure is an expected and uninteresting part of
+/// normal operation, such as a query finding no matching entity.
+pub struct Ignore;
+
+impl<T> From<T> for Ignore {
+    fn from(_: T) -> Self {
+        Self
+    }
+}
+
+impl Failure for Ignore {
+    type Param = ();
+
+    const LEVEL: Level = Level::TRACE;
+
+    fn handle_error(self, _: (), _callsite: Option<&'static impl Callsite>) {}
+}
+
+/// Emit a single `tracing` event for `message` at `level`, attaching the
+/// callsite's file/line when available.
+///
+/// Shared by the built-in `Failure`s so they all format callsites the same
+/// way.
+pub(crate) fn log_at(level: Level, callsite: Option<&'static impl Callsite>, message: &str) {
+    let (file, line) = callsite
+        .map(|c| c.metadata())
+        .map_or((None, None), |m| (m.file(), m.line()));
+    let file = file.unwrap_or("<unknown>");
+    let line = line.unwrap_or(0);
+    let breadcrumb = crate::context::breadcrumb();
+    let message = if breadcrumb.is_empty() {
+        message.to_string()
+    } else {
+        format!("{breadcrumb} error: {message}")
+    };
+    let message = message.as_str();
+    match level {
+        Level::ERROR => tracing::error!(target: "sysfail", file, line, "{message}"),
+        Level::WARN => tracing::warn!(target: "sysfail", file, line, "{message}"),
+        Level::INFO => tracing::info!(target: "sysfail", file, line, "{message}"),
+        Level::DEBUG => tracing::debug!(target: "sysfail", file, line, "{message}"),
+        Level::TRACE => tracing::trace!(target: "sysfail", file, line, "{message}"),
+    }
+}
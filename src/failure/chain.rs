@@ -0,0 +1,122 @@
+//! [`Chain`], a [`Failure`] decorator that logs the full causal chain of an
+//! error instead of only its top-level [`Display`](fmt::Display).
+
+use std::error::Error as StdError;
+use std::fmt::{self, Write};
+use std::marker::PhantomData;
+
+use crate::{Callsite, Failure, Level};
+
+/// Walk `error`'s [`StdError::source`] chain, writing `error` itself then
+/// each successive source on its own indented line.
+fn format_chain(error: &(dyn StdError + 'static)) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    let mut depth = 1;
+    while let Some(cause) = source {
+        let _ = write!(message, "\n{}caused by: {cause}", "  ".repeat(depth));
+        source = cause.source();
+        depth += 1;
+    }
+    message
+}
+
+/// Log the full causal chain of an error, not just its top-level message.
+///
+/// Wraps any `T: std::error::Error` and, before delegating to the inner
+/// `Failure`, walks `source()` to build a multi-line "caused by:" chain
+/// (mirroring how `anyhow` prints its chains), then hands the assembled
+/// string to `F` to log.
+///
+/// ```ignore
+/// #[sysfail(Chain<Log<String>>)]
+/// fn my_system() -> Result<(), MyError> {
+///     Ok(())
+/// }
+/// ```
+pub struct Chain<F = crate::failure::Log<String>> {
+    message: String,
+    _failure: PhantomData<fn(F)>,
+}
+
+impl<T: StdError + 'static, F> From<T> for Chain<F> {
+    fn from(error: T) -> Self {
+        Self { message: format_chain(&error), _failure: PhantomData }
+    }
+}
+
+impl<F> Failure for Chain<F>
+where
+    F: Failure + From<String>,
+{
+    type Param = F::Param;
+
+    const LEVEL: Level = F::LEVEL;
+
+    fn handle_error(self, param: Self::Param, callsite: Option<&'static impl Callsite>) {
+        F::from(self.message).handle_error(param, callsite);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Leaf;
+
+    impl fmt::Display for Leaf {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "leaf error")
+        }
+    }
+
+    impl StdError for Leaf {}
+
+    #[derive(Debug)]
+    struct Wrapper(Leaf);
+
+    impl fmt::Display for Wrapper {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "wrapper error")
+        }
+    }
+
+    impl StdError for Wrapper {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[derive(Debug)]
+    struct DoubleWrapper(Wrapper);
+
+    impl fmt::Display for DoubleWrapper {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "double wrapper error")
+        }
+    }
+
+    impl StdError for DoubleWrapper {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn no_source_just_displays_the_error() {
+        assert_eq!(format_chain(&Leaf), "leaf error");
+    }
+
+    #[test]
+    fn walks_and_indents_the_source_chain() {
+        assert_eq!(format_chain(&Wrapper(Leaf)), "wrapper error\n  caused by: leaf error");
+    }
+
+    #[test]
+    fn increases_indentation_with_depth() {
+        let error = DoubleWrapper(Wrapper(Leaf));
+        let expected = "double wrapper error\n  caused by: wrapper error\n    caused by: leaf error";
+        assert_eq!(format_chain(&error), expected);
+    }
+}
@@ -0,0 +1,132 @@
+//! [`Structured`], a [`Failure`] that emits machine-parseable `tracing`
+//! fields instead of a single formatted string.
+//!
+//! **Status: blocked.** The request behind this module asked for the
+//! `#[sysfail]` macro itself to capture `system`/`elapsed_ms` automatically.
+//! That macro lives in the separate `bevy_mod_sysfail_macros` crate, which
+//! is not part of this repository/series, so that half of the work isn't
+//! done here — tracking it is out of scope for this series and left to a
+//! follow-up against that crate. What *is* here is [`enter`], a
+//! thread-local-backed helper `Structured` reads from; call it by hand at
+//! the top of a system's body if you want `system`/`elapsed_ms` populated.
+//! Without it, both fields come through as an unmistakable "not wired"
+//! sentinel (see [`current`]) rather than a plausible-looking fake value.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use crate::{Callsite, Failure, Level};
+
+thread_local! {
+    static CURRENT_SYSTEM: RefCell<Option<(&'static str, Instant)>> = RefCell::new(None);
+}
+
+/// Restores the previously-running system's entry (if any) on drop.
+///
+/// Hold one of these for the duration of a system's body, obtained from
+/// [`enter`].
+pub struct SystemGuard(Option<(&'static str, Instant)>);
+
+impl Drop for SystemGuard {
+    fn drop(&mut self) {
+        CURRENT_SYSTEM.with(|current| *current.borrow_mut() = self.0.take());
+    }
+}
+
+/// Record that `name` (typically [`std::any::type_name`] of the calling
+/// system) started running now, returning a guard that restores whatever
+/// was running before it once dropped.
+///
+/// Until `#[sysfail]` calls this for you (see the module docs), call it
+/// yourself at the top of a system you're handling with [`Structured`]:
+///
+/// ```ignore
+/// #[sysfail(Structured<anyhow::Error>)]
+/// fn my_system() -> Result<(), anyhow::Error> {
+///     let _system = bevy_mod_sysfail::structured::enter(std::any::type_name_of_val(&my_system));
+///     Ok(())
+/// }
+/// ```
+pub fn enter(name: &'static str) -> SystemGuard {
+    let previous = CURRENT_SYSTEM.with(|current| current.replace(Some((name, Instant::now()))));
+    SystemGuard(previous)
+}
+
+/// The currently-running `#[sysfail]` system's name and how long it's been
+/// running, if any is active on this thread.
+///
+/// `None` until something calls [`enter`] — today that's never `#[sysfail]`
+/// itself, only code that calls `enter` by hand (see the module docs).
+pub fn current() -> Option<(&'static str, Duration)> {
+    CURRENT_SYSTEM.with(|current| current.borrow().map(|(name, start)| (name, start.elapsed())))
+}
+
+/// Sentinel `system` name [`Structured`] reports when nothing called
+/// [`enter`], so a missing wire-up reads as an obvious placeholder in logs
+/// rather than a plausible-looking `"<unknown>"`.
+const NOT_WIRED: &str = "<system unknown: bevy_mod_sysfail::structured::enter() was never called>";
+
+/// Emit a single `tracing` event carrying discrete, indexable fields rather
+/// than one formatted string.
+///
+/// Where [`Log`](crate::prelude::Log) renders `self.0` into a message,
+/// `Structured` attaches `system`, `file`, `line`, `level`, `elapsed_ms` and
+/// `breadcrumb` (the current `with_context!` trail, if any) as separate
+/// fields, so a JSON/kv subscriber downstream can filter and aggregate on
+/// them instead of grepping text.
+///
+/// ```ignore
+/// #[sysfail(Structured<anyhow::Error>)]
+/// fn my_system() -> Result<(), anyhow::Error> {
+///     Ok(())
+/// }
+/// ```
+pub struct Structured<T, Lvl = crate::Error>(pub T, PhantomData<fn(Lvl)>);
+
+impl<T, Lvl> From<T> for Structured<T, Lvl> {
+    fn from(error: T) -> Self {
+        Self(error, PhantomData)
+    }
+}
+
+impl<T: fmt::Display, Lvl: crate::LogLevelModifier> Failure for Structured<T, Lvl> {
+    type Param = ();
+
+    const LEVEL: Level = Lvl::LEVEL;
+
+    fn handle_error(self, _: (), callsite: Option<&'static impl Callsite>) {
+        let metadata = callsite.map(Callsite::metadata);
+        let file = metadata.and_then(|m| m.file()).unwrap_or("<unknown>");
+        let line = metadata.and_then(|m| m.line()).unwrap_or(0);
+        // `elapsed_ms` is NaN rather than 0.0 when unwired, so a downstream
+        // kv/JSON consumer can't mistake "never measured" for "measured and
+        // fast".
+        let (system, elapsed_ms) = match current() {
+            Some((system, elapsed)) => (system, elapsed.as_secs_f64() * 1000.0),
+            None => (NOT_WIRED, f64::NAN),
+        };
+        let breadcrumb = crate::context::breadcrumb();
+        let level = Self::LEVEL;
+        let message = self.0.to_string();
+
+        match level {
+            Level::ERROR => {
+                tracing::error!(target: "sysfail", system, file, line, %level, elapsed_ms, breadcrumb, "{message}")
+            }
+            Level::WARN => {
+                tracing::warn!(target: "sysfail", system, file, line, %level, elapsed_ms, breadcrumb, "{message}")
+            }
+            Level::INFO => {
+                tracing::info!(target: "sysfail", system, file, line, %level, elapsed_ms, breadcrumb, "{message}")
+            }
+            Level::DEBUG => {
+                tracing::debug!(target: "sysfail", system, file, line, %level, elapsed_ms, breadcrumb, "{message}")
+            }
+            Level::TRACE => {
+                tracing::trace!(target: "sysfail", system, file, line, %level, elapsed_ms, breadcrumb, "{message}")
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! [`Syslog`], a [`Failure`] that routes errors to the system logger instead
+//! of `tracing`.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use syslog::{Facility, Formatter3164, Severity};
+
+use crate::{Callsite, Failure, Level};
+
+/// Picks the syslog [`Facility`] a [`Syslog`] failure logs under, the same
+/// way [`LogLevelModifier`](crate::LogLevelModifier) picks a [`Level`].
+pub trait FacilityModifier {
+    /// The [`Facility`] this marker stands for.
+    const FACILITY: Facility;
+}
+
+macro_rules! facility_marker {
+    ($(#[$meta:meta])* $name:ident => $facility:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $name;
+
+        impl FacilityModifier for $name {
+            const FACILITY: Facility = Facility::$facility;
+        }
+    };
+}
+
+facility_marker!(
+    /// `LOG_DAEMON`, appropriate for a headless Bevy server. The default.
+    Daemon => LOG_DAEMON
+);
+facility_marker!(User => LOG_USER);
+facility_marker!(Cron => LOG_CRON);
+facility_marker!(Local0 => LOG_LOCAL0);
+facility_marker!(Local1 => LOG_LOCAL1);
+facility_marker!(Local2 => LOG_LOCAL2);
+facility_marker!(Local3 => LOG_LOCAL3);
+facility_marker!(Local4 => LOG_LOCAL4);
+facility_marker!(Local5 => LOG_LOCAL5);
+facility_marker!(Local6 => LOG_LOCAL6);
+facility_marker!(Local7 => LOG_LOCAL7);
+
+fn severity_for(level: Level) -> Severity {
+    match level {
+        Level::ERROR => Severity::LOG_ERR,
+        Level::WARN => Severity::LOG_WARNING,
+        Level::INFO => Severity::LOG_NOTICE,
+        Level::DEBUG => Severity::LOG_INFO,
+        Level::TRACE => Severity::LOG_DEBUG,
+    }
+}
+
+/// Send the error to the system logger (journald/syslog) via the `syslog`
+/// crate, instead of `tracing`.
+///
+/// The crate's [`Level`] is mapped onto the closest syslog [`Severity`]
+/// (`ERROR`/`WARN`/`INFO`/`DEBUG`/`TRACE` become
+/// `Err`/`Warning`/`Notice`/`Info`/`Debug`), the [`Facility`] to log under is
+/// picked at the type level with `Fac` (defaulting to [`Daemon`]), and the
+/// callsite's file/line is prefixed onto the message so it still shows up
+/// in `journalctl` output.
+///
+/// ```ignore
+/// #[sysfail(Syslog<anyhow::Error>)]
+/// fn daemon_system() -> Result<(), anyhow::Error> {
+///     Ok(())
+/// }
+///
+/// #[sysfail(Syslog<anyhow::Error, Warn, Local0>)]
+/// fn other_daemon_system() -> Result<(), anyhow::Error> {
+///     Ok(())
+/// }
+/// ```
+pub struct Syslog<T, Lvl = crate::Error, Fac = Daemon>(pub T, PhantomData<fn(Lvl, Fac)>);
+
+impl<T, Lvl, Fac> From<T> for Syslog<T, Lvl, Fac> {
+    fn from(error: T) -> Self {
+        Self(error, PhantomData)
+    }
+}
+
+impl<T: fmt::Display, Lvl: crate::LogLevelModifier, Fac: FacilityModifier> Failure
+    for Syslog<T, Lvl, Fac>
+{
+    type Param = ();
+
+    const LEVEL: Level = Lvl::LEVEL;
+
+    fn handle_error(self, _: (), callsite: Option<&'static impl Callsite>) {
+        let metadata = callsite.map(Callsite::metadata);
+        let file = metadata.and_then(|m| m.file()).unwrap_or("<unknown>");
+        let line = metadata.and_then(|m| m.line()).unwrap_or(0);
+        let breadcrumb = crate::context::breadcrumb();
+        let message = if breadcrumb.is_empty() {
+            format!("{file}:{line}: {}", self.0)
+        } else {
+            format!("{file}:{line}: {breadcrumb} error: {}", self.0)
+        };
+
+        let formatter =
+            Formatter3164 { facility: Fac::FACILITY, hostname: None, process: "bevy".into(), pid: 0 };
+        // A fresh connection per error keeps this `Failure` stateless;
+        // daemons that fail every frame should reach for
+        // `Throttle<Syslog<..>, _>` instead.
+        match syslog::unix(formatter) {
+            Ok(mut writer) => {
+                let result = match severity_for(Lvl::LEVEL) {
+                    Severity::LOG_ERR => writer.err(message),
+                    Severity::LOG_WARNING => writer.warning(message),
+                    Severity::LOG_NOTICE => writer.notice(message),
+                    Severity::LOG_INFO => writer.info(message),
+                    _ => writer.debug(message),
+                };
+                if let Err(error) = result {
+                    tracing::error!("failed to write to syslog: {error}");
+                }
+            }
+            Err(error) => tracing::error!("failed to connect to syslog: {error}"),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! [`Tee`], a [`Failure`] combinator that fans one error out to two sinks.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::{Callsite, Failure, Level};
+
+const fn severity_rank(level: Level) -> u8 {
+    match level {
+        Level::ERROR => 0,
+        Level::WARN => 1,
+        Level::INFO => 2,
+        Level::DEBUG => 3,
+        Level::TRACE => 4,
+    }
+}
+
+const fn more_severe(a: Level, b: Level) -> Level {
+    if severity_rank(a) <= severity_rank(b) { a } else { b }
+}
+
+/// Run two `Failure`s on the same error.
+///
+/// Since [`Failure::handle_error`] consumes `self`, `Tee` can't just call it
+/// twice on the inner error: instead it formats the error into an owned
+/// `String` up front and hands that same string to both `A` and `B` (via a
+/// `From<String>` bound), so e.g. `#[sysfail(Tee<Log<String>, Syslog<String>>)]`
+/// both logs the error and sends it to the system logger.
+///
+/// `Tee`'s [`Param`](Failure::Param) is `(A::Param, B::Param)`, and its
+/// [`LEVEL`](Failure::LEVEL) is whichever of the two is more severe.
+pub struct Tee<A, B>(String, PhantomData<fn(A, B)>);
+
+impl<T: fmt::Display, A, B> From<T> for Tee<A, B> {
+    fn from(error: T) -> Self {
+        Self(error.to_string(), PhantomData)
+    }
+}
+
+impl<A, B> Failure for Tee<A, B>
+where
+    A: Failure + From<String>,
+    B: Failure + From<String>,
+{
+    type Param = (A::Param, B::Param);
+
+    const LEVEL: Level = more_severe(A::LEVEL, B::LEVEL);
+
+    fn handle_error(self, (a_param, b_param): Self::Param, callsite: Option<&'static impl Callsite>) {
+        A::from(self.0.clone()).handle_error(a_param, callsite);
+        B::from(self.0).handle_error(b_param, callsite);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_outranks_everything() {
+        assert_eq!(more_severe(Level::ERROR, Level::TRACE), Level::ERROR);
+        assert_eq!(more_severe(Level::TRACE, Level::ERROR), Level::ERROR);
+    }
+
+    #[test]
+    fn picks_the_more_severe_of_adjacent_levels() {
+        assert_eq!(more_severe(Level::WARN, Level::INFO), Level::WARN);
+        assert_eq!(more_severe(Level::INFO, Level::WARN), Level::WARN);
+    }
+
+    #[test]
+    fn a_level_is_at_least_as_severe_as_itself() {
+        assert_eq!(more_severe(Level::DEBUG, Level::DEBUG), Level::DEBUG);
+    }
+}
@@ -0,0 +1,153 @@
+//! [`Throttle`], a [`Failure`] decorator that rate-limits how often its inner
+//! `Failure` actually runs.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::{Callsite, Failure, Level};
+
+/// Last time each callsite was allowed through, plus how many times it was
+/// suppressed since. Keyed by `(TypeId of Throttle<F, MILLIS>, file, line)`: the `TypeId` keeps
+/// two differently-configured `Throttle<F, _>`s composed at the same
+/// callsite (e.g. via `Tee`) from sharing, and corrupting, each other's
+/// suppression state.
+///
+/// This has to be a process-global, not a thread-local: Bevy's default
+/// multi-threaded executor doesn't pin a system to the same worker thread
+/// across ticks, so a thread-local map would find no entry (and let the
+/// message through again) whenever the failing system happened to land on a
+/// different thread than last time.
+static LAST_EMITTED: Mutex<HashMap<(TypeId, &'static str, u32), (Instant, u32)>> =
+    Mutex::new(HashMap::new());
+
+/// Forward to `F` at most once every `MILLIS` milliseconds, per callsite.
+///
+/// Systems that fail every frame would otherwise flood the log with
+/// identical messages. Wrapping the `Failure` in `Throttle` makes it run at
+/// most once per `MILLIS`-millisecond window for a given `#[sysfail]`
+/// callsite; whatever was suppressed in between is folded into a
+/// `(×N in last Mms)` suffix on the next message that does get through.
+///
+/// ```ignore
+/// #[sysfail(Throttle<Log<anyhow::Error>, 1000>)]
+/// fn flaky_system(res: Res<Flaky>) -> Result<(), anyhow::Error> {
+///     res.check()?;
+///     Ok(())
+/// }
+/// ```
+pub struct Throttle<F, const MILLIS: u64 = 1000> {
+    message: String,
+    _failure: PhantomData<fn(F)>,
+}
+
+impl<T: fmt::Display, F, const MILLIS: u64> From<T> for Throttle<F, MILLIS> {
+    fn from(error: T) -> Self {
+        Self { message: error.to_string(), _failure: PhantomData }
+    }
+}
+
+impl<F, const MILLIS: u64> Failure for Throttle<F, MILLIS>
+where
+    F: Failure + From<String> + 'static,
+{
+    type Param = F::Param;
+
+    const LEVEL: Level = F::LEVEL;
+
+    fn handle_error(self, param: Self::Param, callsite: Option<&'static impl Callsite>) {
+        let metadata = callsite.map(Callsite::metadata);
+        let key = (
+            TypeId::of::<Self>(),
+            metadata.and_then(|m| m.file()).unwrap_or("<unknown>"),
+            metadata.and_then(|m| m.line()).unwrap_or(0),
+        );
+        let window = Duration::from_millis(MILLIS);
+        let now = Instant::now();
+
+        let suppressed_count = {
+            let mut last_emitted = LAST_EMITTED.lock().unwrap();
+            record_and_check(&mut last_emitted, key, now, window)
+        };
+
+        let Some(suppressed_count) = suppressed_count else { return };
+        let message = if suppressed_count > 0 {
+            format!("{} (×{suppressed_count} in last {MILLIS}ms)", self.message)
+        } else {
+            self.message
+        };
+        F::from(message).handle_error(param, callsite);
+    }
+}
+
+/// The suppression state machine, decoupled from the global map and the
+/// `Failure`/`Callsite` machinery so it's easy to unit test: given `key`'s
+/// current entry in `map` (if any), decide whether this call should be
+/// allowed through, returning `Some(suppressed_count)` (and resetting the
+/// entry) when it should, or `None` (after bumping the suppressed count)
+/// when it should be dropped.
+fn record_and_check<K: std::hash::Hash + Eq>(
+    map: &mut HashMap<K, (Instant, u32)>,
+    key: K,
+    now: Instant,
+    window: Duration,
+) -> Option<u32> {
+    match map.get_mut(&key) {
+        Some((last, suppressed)) if now.duration_since(*last) < window => {
+            *suppressed += 1;
+            None
+        }
+        Some(entry) => Some(std::mem::replace(entry, (now, 0)).1),
+        None => {
+            map.insert(key, (now, 0));
+            Some(0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_call_for_a_key_always_emits() {
+        let mut map = HashMap::new();
+        let now = Instant::now();
+        assert_eq!(record_and_check(&mut map, "k", now, Duration::from_millis(1000)), Some(0));
+    }
+
+    #[test]
+    fn calls_within_the_window_are_suppressed_and_counted() {
+        let mut map = HashMap::new();
+        let now = Instant::now();
+        record_and_check(&mut map, "k", now, Duration::from_millis(1000));
+        assert_eq!(record_and_check(&mut map, "k", now, Duration::from_millis(1000)), None);
+        assert_eq!(record_and_check(&mut map, "k", now, Duration::from_millis(1000)), None);
+        assert_eq!(map.get("k").unwrap().1, 2);
+    }
+
+    #[test]
+    fn call_after_the_window_emits_and_resets_the_suppressed_count() {
+        let mut map = HashMap::new();
+        let t0 = Instant::now();
+        record_and_check(&mut map, "k", t0, Duration::from_millis(10));
+        record_and_check(&mut map, "k", t0, Duration::from_millis(10));
+        let t1 = t0 + Duration::from_millis(20);
+
+        let suppressed_count = record_and_check(&mut map, "k", t1, Duration::from_millis(10));
+
+        assert_eq!(suppressed_count, Some(1));
+        assert_eq!(map.get("k").unwrap().1, 0);
+    }
+
+    #[test]
+    fn distinct_keys_are_throttled_independently() {
+        let mut map = HashMap::new();
+        let now = Instant::now();
+        assert_eq!(record_and_check(&mut map, "a", now, Duration::from_millis(1000)), Some(0));
+        assert_eq!(record_and_check(&mut map, "b", now, Duration::from_millis(1000)), Some(0));
+    }
+}
@@ -0,0 +1,48 @@
+//! Zero-sized marker types used to pick the [`tracing::Level`] at which a
+//! [`Failure`](crate::Failure) implementation logs.
+//!
+//! Several built-in `Failure`s (and the ones in `examples/`) are generic over
+//! a level, so that `#[sysfail(Log<MyError, Warn>)]` and
+//! `#[sysfail(Log<MyError, Error>)]` can share the same implementation.
+
+pub use tracing::Level;
+
+/// Implemented by the marker types in this module, lets a `Failure` pick its
+/// [`Level`] at the type level rather than at runtime.
+pub trait LogLevelModifier {
+    /// The [`Level`] this marker stands for.
+    const LEVEL: Level;
+}
+
+macro_rules! level_marker {
+    ($(#[$meta:meta])* $name:ident => $level:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $name;
+
+        impl LogLevelModifier for $name {
+            const LEVEL: Level = Level::$level;
+        }
+    };
+}
+
+level_marker!(
+    /// Log at [`Level::ERROR`].
+    Error => ERROR
+);
+level_marker!(
+    /// Log at [`Level::WARN`].
+    Warn => WARN
+);
+level_marker!(
+    /// Log at [`Level::INFO`].
+    Info => INFO
+);
+level_marker!(
+    /// Log at [`Level::DEBUG`].
+    Debug => DEBUG
+);
+level_marker!(
+    /// Log at [`Level::TRACE`].
+    Trace => TRACE
+);
@@ -0,0 +1,43 @@
+//! Conditionally log, display or otherwise handle the errors returned by
+//! your bevy systems.
+//!
+//! Annotate a system with `#[sysfail]` and have it return a `Result` (or
+//! `Option`) instead of panicking or silently swallowing the error:
+//!
+//! ```ignore
+//! #[sysfail(Log<anyhow::Error>)]
+//! fn my_system(query: Query<&Foo>) -> Result<(), anyhow::Error> {
+//!     let foo = query.get_single()?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! What happens when the system errors is controlled by the [`Failure`]
+//! passed to `#[sysfail(..)]`. This crate ships a few ([`failure::Log`],
+//! [`failure::Ignore`] and the combinators in [`failure`]), and you can
+//! implement your own, see `examples/custom_failure.rs`.
+
+mod callsite;
+mod failure;
+mod level;
+
+pub mod context;
+
+pub use bevy_mod_sysfail_macros::sysfail;
+pub use callsite::Callsite;
+pub use failure::structured;
+pub use failure::syslog;
+pub use failure::Failure;
+pub use level::{Debug, Error, Info, Level, LogLevelModifier, Trace, Warn};
+
+/// Commonly used items, glob-import this in modules that define `#[sysfail]`
+/// systems.
+pub mod prelude {
+    pub use crate::failure::chain::Chain;
+    pub use crate::failure::structured::Structured;
+    pub use crate::failure::syslog::{Daemon, Syslog};
+    pub use crate::failure::tee::Tee;
+    pub use crate::failure::throttle::Throttle;
+    pub use crate::failure::{Ignore, Log};
+    pub use crate::{sysfail, with_context, Debug, Error, Failure, Info, Trace, Warn};
+}